@@ -0,0 +1,105 @@
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{Error as PasswordHashError, PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use axum::async_trait;
+use axum::extract::{Extension, FromRequestParts};
+use axum::headers::authorization::{Authorization, Bearer};
+use axum::http::request::Parts;
+use axum::{RequestPartsExt, TypedHeader};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::configuration::Config;
+use crate::errors::Error;
+
+/// Hashes a plaintext password into an Argon2id PHC string suitable for storage.
+pub fn hash_password(password: &str) -> Result<String, PasswordHashError> {
+    let salt = SaltString::generate(OsRng);
+    let hash = Argon2::default().hash_password(password.as_bytes(), &salt)?;
+    Ok(hash.to_string())
+}
+
+/// Checks a candidate password against a stored Argon2 PHC hash.
+pub fn verify_password(candidate: &str, phc: &str) -> Result<bool, PasswordHashError> {
+    let parsed_hash = PasswordHash::new(phc)?;
+    Ok(Argon2::default()
+        .verify_password(candidate.as_bytes(), &parsed_hash)
+        .is_ok())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TokenClaims {
+    /// The authenticated user's id, as a string (JWT spec requires `sub` be a string).
+    pub sub: String,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+fn unix_timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is before the epoch")
+        .as_secs() as i64
+}
+
+/// Issues a signed JWT for `user_id`, valid for `config.jwt_maxage` minutes.
+pub fn generate_token(user_id: i64, config: &Config) -> Result<String, jsonwebtoken::errors::Error> {
+    let iat = unix_timestamp();
+    let exp = iat + config.jwt_maxage * 60;
+
+    let claims = TokenClaims {
+        sub: user_id.to_string(),
+        iat,
+        exp,
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(config.jwt_secret.as_bytes()),
+    )
+}
+
+/// Extractor that authenticates a request from its `Authorization: Bearer` header,
+/// yielding the signed-in user's id to the handler.
+pub struct AccessClaims {
+    pub user_id: i64,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AccessClaims
+where
+    S: Send + Sync,
+{
+    type Rejection = Error;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Extension(config) = parts
+            .extract::<Extension<Arc<Config>>>()
+            .await
+            .map_err(|_| Error::Internal("missing config extension".to_string()))?;
+
+        let TypedHeader(Authorization(bearer)) = parts
+            .extract::<TypedHeader<Authorization<Bearer>>>()
+            .await
+            .map_err(|_| Error::InvalidToken)?;
+
+        let token_data = decode::<TokenClaims>(
+            bearer.token(),
+            &DecodingKey::from_secret(config.jwt_secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map_err(|_| Error::InvalidToken)?;
+
+        let user_id = token_data
+            .claims
+            .sub
+            .parse::<i64>()
+            .map_err(|_| Error::InvalidToken)?;
+
+        Ok(AccessClaims { user_id })
+    }
+}
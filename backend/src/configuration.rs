@@ -0,0 +1,51 @@
+use std::env;
+
+/// Newtype around the port we bind to, so it can't be confused with other
+/// `u16` config values (e.g. a future metrics port).
+pub struct Port(pub u16);
+
+pub struct Config {
+    pub port: Port,
+    pub database_url: String,
+    pub jwt_secret: String,
+    /// How long issued JWTs stay valid, in minutes.
+    pub jwt_maxage: i64,
+    /// `tower_http` compression quality, 0 (fastest) to 11 (smallest).
+    pub compression_level: i32,
+    /// Responses smaller than this (in bytes) are sent uncompressed.
+    pub compression_min_size: u16,
+}
+
+/// Loads configuration from the environment, panicking with a clear message
+/// if anything required is missing or malformed.
+pub fn load_config() -> Config {
+    let port = env::var("PORT")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(8000);
+
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let jwt_secret = env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+    let jwt_maxage = env::var("JWT_MAXAGE")
+        .expect("JWT_MAXAGE must be set")
+        .parse()
+        .expect("JWT_MAXAGE must be an integer number of minutes");
+
+    let compression_level = env::var("COMPRESSION_LEVEL")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(4);
+    let compression_min_size = env::var("COMPRESSION_MIN_SIZE")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(256);
+
+    Config {
+        port: Port(port),
+        database_url,
+        jwt_secret,
+        jwt_maxage,
+        compression_level,
+        compression_min_size,
+    }
+}
@@ -0,0 +1,268 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use axum::extract::{Extension, Multipart, Path, State};
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use axum::Json;
+use image::imageops::FilterType;
+use image::{GenericImageView, ImageOutputFormat};
+use sqlx::PgPool;
+
+use crate::auth::{generate_token, hash_password, verify_password, AccessClaims};
+use crate::configuration::Config;
+use crate::errors::{Error, ErrorBody};
+use crate::ids::UserId;
+use crate::models::{CreateUserSchema, LoginResponse, LoginUserSchema, UserModel};
+
+/// Avatars are re-encoded as PNG and scaled down, keeping their aspect ratio,
+/// so that neither side is larger than this.
+const AVATAR_MAX_DIM: u32 = 256;
+
+#[utoipa::path(
+    get,
+    path = "/users",
+    tag = "users",
+    responses(
+        (status = 200, description = "All users", body = [UserModel]),
+        (status = 500, description = "Database error", body = ErrorBody),
+    )
+)]
+pub async fn list_users(State(pool): State<PgPool>) -> Result<Json<Vec<UserModel>>, Error> {
+    let list_of_users: Vec<UserModel> = sqlx::query_as!(UserModel, "SELECT * FROM user_profile")
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(Json(list_of_users))
+}
+
+pub async fn create_user(pool: &PgPool, user: CreateUserSchema) -> Result<UserModel, Error> {
+    let password_hash =
+        hash_password(&user.password).map_err(|e| Error::Internal(e.to_string()))?;
+
+    let user_model: UserModel = sqlx::query_as!(
+        UserModel,
+        r#"
+        INSERT INTO user_profile (username, first_name, last_name, email, password)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING *
+        "#,
+        user.username,
+        user.first_name,
+        user.last_name,
+        user.email,
+        password_hash
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(user_model)
+}
+
+#[utoipa::path(
+    post,
+    path = "/signup",
+    tag = "users",
+    request_body = CreateUserSchema,
+    responses(
+        (status = 201, description = "User created", body = UserModel),
+        (status = 409, description = "Username or email already in use", body = ErrorBody),
+    )
+)]
+pub async fn signup_user(
+    State(pool): State<PgPool>,
+    Json(user): Json<CreateUserSchema>,
+) -> Result<(StatusCode, Json<UserModel>), Error> {
+    let user_model = create_user(&pool, user).await?;
+    Ok((StatusCode::CREATED, Json(user_model)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/login",
+    tag = "users",
+    request_body = LoginUserSchema,
+    responses(
+        (status = 200, description = "Signed JWT", body = LoginResponse),
+        (status = 401, description = "Invalid email or password", body = ErrorBody),
+    )
+)]
+pub async fn login_user(
+    State(pool): State<PgPool>,
+    Extension(config): Extension<Arc<Config>>,
+    Json(credentials): Json<LoginUserSchema>,
+) -> Result<Json<LoginResponse>, Error> {
+    let user: Option<UserModel> = sqlx::query_as!(
+        UserModel,
+        "SELECT * FROM user_profile WHERE email = $1",
+        credentials.email
+    )
+    .fetch_optional(&pool)
+    .await?;
+
+    let user = user.ok_or(Error::InvalidCredentials)?;
+
+    let password_matches = verify_password(&credentials.password, &user.password)
+        .map_err(|e| Error::Internal(e.to_string()))?;
+
+    if !password_matches {
+        return Err(Error::InvalidCredentials);
+    }
+
+    let token = generate_token(user.id, &config).map_err(|e| Error::Internal(e.to_string()))?;
+
+    Ok(Json(LoginResponse { token }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/users/{id}",
+    tag = "users",
+    params(("id" = String, Path, description = "Sqids-encoded user id")),
+    responses(
+        (status = 200, description = "The user", body = UserModel),
+        (status = 404, description = "No such user", body = ErrorBody),
+    )
+)]
+pub async fn get_user(
+    State(pool): State<PgPool>,
+    Path(token): Path<String>,
+) -> Result<Json<UserModel>, Error> {
+    let UserId(id) = UserId::decode(&token)?;
+
+    let user: Option<UserModel> =
+        sqlx::query_as!(UserModel, "SELECT * FROM user_profile WHERE id = $1", id)
+            .fetch_optional(&pool)
+            .await?;
+
+    user.map(Json).ok_or(Error::NotFound)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/users/{id}",
+    tag = "users",
+    params(("id" = String, Path, description = "Sqids-encoded user id")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 204, description = "User deleted"),
+        (status = 403, description = "Not the account owner", body = ErrorBody),
+        (status = 404, description = "No such user", body = ErrorBody),
+    )
+)]
+pub async fn delete_user(
+    State(pool): State<PgPool>,
+    Path(token): Path<String>,
+    claims: AccessClaims,
+) -> Result<StatusCode, Error> {
+    let UserId(id) = UserId::decode(&token)?;
+
+    if claims.user_id != id {
+        return Err(Error::Forbidden);
+    }
+
+    let result = sqlx::query!("DELETE FROM user_profile WHERE id = $1", id)
+        .execute(&pool)
+        .await?;
+
+    if result.rows_affected() == 1 {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(Error::NotFound)
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/{id}/avatar",
+    tag = "users",
+    params(("id" = String, Path, description = "Sqids-encoded user id")),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 204, description = "Avatar updated"),
+        (status = 400, description = "Missing or unrecognizable image upload", body = ErrorBody),
+        (status = 403, description = "Not the account owner", body = ErrorBody),
+    )
+)]
+pub async fn upload_avatar(
+    State(pool): State<PgPool>,
+    Path(token): Path<String>,
+    claims: AccessClaims,
+    mut multipart: Multipart,
+) -> Result<StatusCode, Error> {
+    let UserId(id) = UserId::decode(&token)?;
+
+    if claims.user_id != id {
+        return Err(Error::Forbidden);
+    }
+
+    let mut avatar_field = None;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|_| Error::BadRequest("malformed multipart body".to_string()))?
+    {
+        if field.name() == Some("avatar") {
+            avatar_field = Some(field);
+            break;
+        }
+    }
+
+    let field = avatar_field.ok_or_else(|| Error::BadRequest("missing `avatar` field".to_string()))?;
+    let upload = field
+        .bytes()
+        .await
+        .map_err(|_| Error::BadRequest("malformed multipart body".to_string()))?;
+
+    image::guess_format(&upload).map_err(|_| Error::BadRequest("not a recognizable image".to_string()))?;
+
+    let decoded = image::load_from_memory(&upload)
+        .map_err(|_| Error::BadRequest("failed to decode image".to_string()))?;
+    let avatar = if decoded.width() > AVATAR_MAX_DIM || decoded.height() > AVATAR_MAX_DIM {
+        decoded.resize(AVATAR_MAX_DIM, AVATAR_MAX_DIM, FilterType::Lanczos3)
+    } else {
+        decoded
+    };
+
+    let mut encoded = Vec::new();
+    avatar
+        .write_to(&mut Cursor::new(&mut encoded), ImageOutputFormat::Png)
+        .map_err(|e| Error::Internal(e.to_string()))?;
+
+    sqlx::query!(
+        "UPDATE user_profile SET avatar = $1 WHERE id = $2",
+        encoded,
+        id
+    )
+    .execute(&pool)
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/users/{id}/avatar",
+    tag = "users",
+    params(("id" = String, Path, description = "Sqids-encoded user id")),
+    responses(
+        (status = 200, description = "Avatar PNG", content_type = "image/png"),
+        (status = 404, description = "No avatar set", body = ErrorBody),
+    )
+)]
+pub async fn get_avatar(
+    State(pool): State<PgPool>,
+    Path(token): Path<String>,
+) -> Result<impl IntoResponse, Error> {
+    let UserId(id) = UserId::decode(&token)?;
+
+    let avatar: Option<Vec<u8>> =
+        sqlx::query_scalar!("SELECT avatar FROM user_profile WHERE id = $1", id)
+            .fetch_optional(&pool)
+            .await?
+            .flatten();
+
+    let avatar = avatar.ok_or(Error::NotFound)?;
+
+    Ok(([(header::CONTENT_TYPE, "image/png")], avatar))
+}
@@ -0,0 +1,13 @@
+use std::time::Duration;
+
+use sqlx::postgres::{PgPool, PgPoolOptions};
+
+/// Builds the Postgres connection pool used as the app's axum state.
+pub async fn init_pool(database_url: &str) -> PgPool {
+    PgPoolOptions::new()
+        .max_connections(5)
+        .acquire_timeout(Duration::from_secs(3))
+        .connect(database_url)
+        .await
+        .expect("failed to connect to Postgres")
+}
@@ -0,0 +1,100 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// Crate-wide error type. Every handler returns `Result<T, Error>` so the
+/// response shape is consistent no matter where the error originates.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("database error")]
+    Sqlx(sqlx::Error),
+
+    #[error("not found")]
+    NotFound,
+
+    #[error("a user with that username or email already exists")]
+    UserExists,
+
+    #[error("invalid email or password")]
+    InvalidCredentials,
+
+    #[error("invalid or expired token")]
+    InvalidToken,
+
+    #[error("not allowed to modify this resource")]
+    Forbidden,
+
+    #[error("{0}")]
+    BadRequest(String),
+
+    #[error("internal error")]
+    Internal(String),
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::Database(db_error) if db_error.is_unique_violation() => {
+                let pg_error = db_error.downcast::<sqlx::postgres::PgDatabaseError>();
+                match pg_error.table() {
+                    Some("user_profile") => Error::UserExists,
+                    _ => Error::Sqlx(sqlx::Error::Database(pg_error)),
+                }
+            }
+            other => Error::Sqlx(other),
+        }
+    }
+}
+
+impl Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::Sqlx(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::NotFound => StatusCode::NOT_FOUND,
+            Error::UserExists => StatusCode::CONFLICT,
+            Error::InvalidCredentials => StatusCode::UNAUTHORIZED,
+            Error::InvalidToken => StatusCode::UNAUTHORIZED,
+            Error::Forbidden => StatusCode::FORBIDDEN,
+            Error::BadRequest(_) => StatusCode::BAD_REQUEST,
+            Error::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// The message sent to the client. Internal errors get a fixed, safe
+    /// message here; the real detail only goes to the logs, so library
+    /// internals (argon2/jsonwebtoken/image errors, etc.) never reach callers.
+    fn client_message(&self) -> String {
+        match self {
+            Error::Internal(detail) => {
+                tracing::error!(error = %detail, "internal error");
+                "internal server error".to_string()
+            }
+            other => other.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ErrorBody {
+    status: u16,
+    message: String,
+}
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+        let message = self.client_message();
+
+        (
+            status,
+            Json(ErrorBody {
+                status: status.as_u16(),
+                message,
+            }),
+        )
+            .into_response()
+    }
+}
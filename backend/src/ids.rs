@@ -0,0 +1,50 @@
+use std::sync::OnceLock;
+
+use serde::Serialize;
+use sqids::Sqids;
+
+use crate::errors::Error;
+
+fn sqids() -> &'static Sqids {
+    static INSTANCE: OnceLock<Sqids> = OnceLock::new();
+    INSTANCE.get_or_init(|| {
+        Sqids::builder()
+            .alphabet(
+                "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789"
+                    .chars()
+                    .collect(),
+            )
+            .min_length(8)
+            .build()
+            .expect("invalid sqids configuration")
+    })
+}
+
+/// A short, URL-safe, non-sequential token standing in for a user's internal
+/// `BIGINT` primary key at the API boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserId(pub i64);
+
+impl UserId {
+    /// Decodes a token from a path parameter back into the underlying row id.
+    pub fn decode(token: &str) -> Result<Self, Error> {
+        match sqids().decode(token).as_slice() {
+            [id] => Ok(UserId(*id as i64)),
+            _ => Err(Error::NotFound),
+        }
+    }
+
+    /// Encodes this id into its short, URL-safe token form.
+    pub fn encode(self) -> String {
+        sqids().encode(&[self.0 as u64]).unwrap_or_default()
+    }
+}
+
+impl Serialize for UserId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.encode())
+    }
+}
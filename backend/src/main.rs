@@ -7,36 +7,64 @@ use axum::{
 use tower::ServiceBuilder;
 
 use sqlx::postgres::{PgPool, PgPoolOptions};
-use std::{net::SocketAddr, time::Duration};
-use tower_http::{add_extension::AddExtensionLayer, cors::CorsLayer, trace::TraceLayer};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+use tower_http::{
+    add_extension::AddExtensionLayer,
+    compression::{predicate::SizeAbove, CompressionLayer, CompressionLevel},
+    cors::CorsLayer,
+    decompression::RequestDecompressionLayer,
+    trace::TraceLayer,
+};
 use tracing_subscriber::EnvFilter;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
 
+mod auth;
 mod db;
 mod routes;
 mod configuration;
 mod controllers;
 mod errors;
+mod ids;
 mod models;
+mod openapi;
 
 #[cfg(test)]
 mod tests;
 
 #[tokio::main]
 async fn main() {
-    let config = configuration::load_config();
+    let config = Arc::new(configuration::load_config());
     let port = config.port.0;
 
     tracing_subscriber::fmt()
         .with_env_filter(EnvFilter::from_default_env())
         .pretty()
         .init();
-  
+
+    let pool = db::init_pool(&config.database_url).await;
+
+    let compression_layer = CompressionLayer::new()
+        .gzip(true)
+        .br(true)
+        .quality(CompressionLevel::Precise(config.compression_level))
+        .compress_when(SizeAbove::new(config.compression_min_size));
+
+    let middleware_stack = ServiceBuilder::new()
+        .layer(TraceLayer::new_for_http())
+        .layer(CorsLayer::permissive())
+        .layer(AddExtensionLayer::new(config.clone()))
+        .layer(RequestDecompressionLayer::new())
+        .layer(compression_layer);
+
     // Build our server
     let app = Router::new()
         .merge(routes::user_router())
         .merge(routes::channel_router())
         .merge(routes::message_router())
-        .layer(middleware_stack);
+        .merge(SwaggerUi::new("/swagger-ui").url("/openapi.json", openapi::ApiDoc::openapi()))
+        .layer(middleware_stack)
+        .with_state(pool);
 
     // Run our service with hyper
     let addr = SocketAddr::from(([127, 0, 0, 1], port));
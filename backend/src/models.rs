@@ -0,0 +1,57 @@
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+use crate::ids::UserId;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CreateUserSchema {
+    pub username: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub password: String,
+}
+
+// Likely want to add 'Optional' fields for last name
+// If Optional fields added, change .fetch_* to .fetch_optional(...)
+#[derive(Debug, Clone, FromRow, ToSchema)]
+pub struct UserModel {
+    #[schema(value_type = String)]
+    pub id: i64,
+    pub username: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    #[schema(ignore)]
+    pub password: String,
+}
+
+// Serialized by hand rather than derived so `id` goes out as an encoded
+// `UserId` token and `password` never leaves the process at all.
+impl Serialize for UserModel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UserModel", 5)?;
+        state.serialize_field("id", &UserId(self.id))?;
+        state.serialize_field("username", &self.username)?;
+        state.serialize_field("first_name", &self.first_name)?;
+        state.serialize_field("last_name", &self.last_name)?;
+        state.serialize_field("email", &self.email)?;
+        state.end()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct LoginUserSchema {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LoginResponse {
+    pub token: String,
+}
@@ -0,0 +1,47 @@
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use crate::controllers::users;
+use crate::errors::ErrorBody;
+use crate::models::{CreateUserSchema, LoginResponse, LoginUserSchema, UserModel};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        users::list_users,
+        users::signup_user,
+        users::login_user,
+        users::get_user,
+        users::delete_user,
+        users::upload_avatar,
+        users::get_avatar,
+    ),
+    components(schemas(
+        CreateUserSchema,
+        LoginUserSchema,
+        LoginResponse,
+        UserModel,
+        ErrorBody
+    )),
+    tags((name = "users", description = "User accounts")),
+    modifiers(&SecurityAddon)
+)]
+pub struct ApiDoc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                SecurityScheme::Http(
+                    HttpBuilder::new()
+                        .scheme(HttpAuthScheme::Bearer)
+                        .bearer_format("JWT")
+                        .build(),
+                ),
+            );
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use axum::routing::{get, post};
+use axum::Router;
+use sqlx::PgPool;
+
+use crate::controllers::users;
+
+pub fn user_router() -> Router<PgPool> {
+    Router::new()
+        .route("/users", get(users::list_users))
+        .route(
+            "/users/:id",
+            get(users::get_user).delete(users::delete_user),
+        )
+        .route(
+            "/users/:id/avatar",
+            post(users::upload_avatar).get(users::get_avatar),
+        )
+        .route("/signup", post(users::signup_user))
+        .route("/login", post(users::login_user))
+}
+
+pub fn channel_router() -> Router<PgPool> {
+    Router::new()
+}
+
+pub fn message_router() -> Router<PgPool> {
+    Router::new()
+}
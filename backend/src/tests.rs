@@ -0,0 +1,53 @@
+use crate::auth::{generate_token, hash_password, verify_password};
+use crate::configuration::{Config, Port};
+use crate::ids::UserId;
+use jsonwebtoken::{decode, DecodingKey, Validation};
+
+fn test_config() -> Config {
+    Config {
+        port: Port(8000),
+        database_url: "postgres://localhost/test".to_string(),
+        jwt_secret: "test-secret".to_string(),
+        jwt_maxage: 60,
+        compression_level: 4,
+        compression_min_size: 256,
+    }
+}
+
+#[test]
+fn hash_password_round_trips_through_verify_password() {
+    let hash = hash_password("correct horse battery staple").unwrap();
+
+    assert!(verify_password("correct horse battery staple", &hash).unwrap());
+    assert!(!verify_password("wrong password", &hash).unwrap());
+}
+
+#[test]
+fn generate_token_round_trips_through_decode() {
+    let config = test_config();
+    let token = generate_token(42, &config).unwrap();
+
+    let claims = decode::<crate::auth::TokenClaims>(
+        &token,
+        &DecodingKey::from_secret(config.jwt_secret.as_bytes()),
+        &Validation::default(),
+    )
+    .unwrap()
+    .claims;
+
+    assert_eq!(claims.sub, "42");
+    assert_eq!(claims.exp - claims.iat, config.jwt_maxage * 60);
+}
+
+#[test]
+fn user_id_round_trips_through_sqids() {
+    let id = UserId(12345);
+    let token = id.encode();
+
+    assert_eq!(UserId::decode(&token).unwrap(), id);
+}
+
+#[test]
+fn user_id_decode_rejects_garbage_tokens() {
+    assert!(UserId::decode("not-a-real-token").is_err());
+}